@@ -3,6 +3,7 @@ use std::fmt;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
+use clap::parser::ValueSource;
 use clap::{builder::ValueParser, Arg, ArgAction, Command};
 
 /// Represents the type of a script file based on its extension.
@@ -40,6 +41,15 @@ impl ScriptType {
             _ => Err("Unsupported file type (supported: .psjs, .jsx, .js)".into()),
         }
     }
+
+    /// The canonical extension (without leading dot) for this script type.
+    fn extension(&self) -> &'static str {
+        match self {
+            ScriptType::Psjs => "psjs",
+            ScriptType::Jsx => "jsx",
+            ScriptType::Js => "js",
+        }
+    }
 }
 
 /// Target version of the application
@@ -47,11 +57,22 @@ impl ScriptType {
 /// Latest - latest version of the application
 /// Beta - beta version of the application
 /// Year(u16) - year of the application release
+/// AtLeast(u16) - highest release that is at least the given year (e.g. `>=2022`)
 #[derive(Clone, Debug)]
 enum TargetVersion {
     Latest,
     Beta,
     Year(u16),
+    AtLeast(u16),
+}
+
+/// Parse a four-character `20XX` year token.
+fn parse_year_arg(s: &str) -> Result<u16, String> {
+    if s.len() == 4 && s.starts_with("20") {
+        s.parse::<u16>().map_err(|_| format!("Invalid year: {}", s))
+    } else {
+        Err(format!("Invalid year: {}. Use 20XX", s))
+    }
 }
 
 fn parse_target(s: &str) -> Result<TargetVersion, String> {
@@ -59,24 +80,132 @@ fn parse_target(s: &str) -> Result<TargetVersion, String> {
         "latest" => Ok(TargetVersion::Latest),
         "beta" => Ok(TargetVersion::Beta),
         _ => {
-            if s.len() == 4 && s.starts_with("20") {
-                s.parse::<u16>()
-                    .map(TargetVersion::Year)
-                    .map_err(|_| format!("Invalid year: {}", s))
+            if let Some(rest) = s.strip_prefix(">=") {
+                parse_year_arg(rest).map(TargetVersion::AtLeast)
+            } else if s.starts_with("20") {
+                parse_year_arg(s).map(TargetVersion::Year)
             } else {
-                Err(format!("Invalid target: {}. Use: latest, beta, or 20XX", s))
+                Err(format!(
+                    "Invalid target: {}. Use: latest, beta, 20XX, or >=20XX",
+                    s
+                ))
             }
         }
     }
 }
 
+/// Defaults resolved from the config file and environment.
+///
+/// Precedence runs config file < `HEYPS_*` environment < explicit CLI flag;
+/// this struct holds the file-then-env layer, which main overrides with any
+/// flags the user passed.
+struct Config {
+    app: String,
+    target: String,
+    timeout: u64,
+    verbose: bool,
+    /// Extra directories to scan for `.app` bundles when Spotlight can't help.
+    extra_app_dirs: Vec<PathBuf>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            app: "ps".to_string(),
+            target: "latest".to_string(),
+            timeout: 0,
+            verbose: false,
+            extra_app_dirs: Vec::new(),
+        }
+    }
+}
+
+/// Split a directory list (config value or env var) on the platform separator
+/// (`:` on Unix, `;` on Windows), so drive-letter paths survive on Windows.
+fn parse_dir_list(s: &str) -> Vec<PathBuf> {
+    std::env::split_paths(s)
+        .filter(|p| !p.as_os_str().is_empty())
+        .collect()
+}
+
+/// Path to the user config file, `~/.config/heyps/config.toml`.
+fn config_path() -> Option<PathBuf> {
+    std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".config/heyps/config.toml"))
+}
+
+/// Interpret a boolean-ish config/env value (`true`, `1`, `yes`, `on`).
+fn parse_config_bool(s: &str) -> bool {
+    matches!(s.trim().to_lowercase().as_str(), "true" | "1" | "yes" | "on")
+}
+
+/// Apply the flat `key = value` pairs from a config file onto `cfg`.
+///
+/// Only the handful of known keys are honoured; comments (`#`), blank lines
+/// and `[section]` headers are ignored.
+fn apply_config_file(cfg: &mut Config, text: &str) {
+    for line in text.lines() {
+        let line = line.split('#').next().unwrap_or("").trim();
+        if line.is_empty() || line.starts_with('[') {
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let key = key.trim();
+        let value = value.trim().trim_matches('"');
+        match key {
+            "app" => cfg.app = value.to_string(),
+            "target" => cfg.target = value.to_string(),
+            "timeout" => {
+                if let Ok(n) = value.parse::<u64>() {
+                    cfg.timeout = n;
+                }
+            }
+            "verbose" => cfg.verbose = parse_config_bool(value),
+            "app_dirs" => cfg.extra_app_dirs = parse_dir_list(value),
+            _ => {}
+        }
+    }
+}
+
+/// Load defaults from the config file, then let `HEYPS_*` env vars override.
+fn load_config() -> Config {
+    let mut cfg = Config::default();
+    if let Some(path) = config_path()
+        && let Ok(text) = std::fs::read_to_string(&path)
+    {
+        apply_config_file(&mut cfg, &text);
+    }
+    if let Ok(v) = std::env::var("HEYPS_APP") {
+        cfg.app = v;
+    }
+    if let Ok(v) = std::env::var("HEYPS_TARGET") {
+        cfg.target = v;
+    }
+    if let Ok(v) = std::env::var("HEYPS_TIMEOUT")
+        && let Ok(n) = v.parse::<u64>()
+    {
+        cfg.timeout = n;
+    }
+    if let Ok(v) = std::env::var("HEYPS_VERBOSE") {
+        cfg.verbose = parse_config_bool(&v);
+    }
+    if let Ok(v) = std::env::var("HEYPS_APP_DIRS") {
+        cfg.extra_app_dirs = parse_dir_list(&v);
+    }
+    cfg
+}
+
 /// Abbreviation of the application
-/// e.g. ps, ai, ae
+/// e.g. ps, ai, ae, id, pr, br
 #[derive(Clone, Debug, PartialEq, Eq)]
 enum AppAbbr {
     Ps,
     Ai,
     Ae,
+    Id,
+    Pr,
+    Br,
 }
 
 /// Parse an application abbreviation from a string
@@ -88,38 +217,172 @@ impl FromStr for AppAbbr {
             "ps" => Ok(AppAbbr::Ps),
             "ai" => Ok(AppAbbr::Ai),
             "ae" => Ok(AppAbbr::Ae),
-            _ => Err("Unsupported application. Use: ps|ai|ae".into()),
+            "id" => Ok(AppAbbr::Id),
+            "pr" => Ok(AppAbbr::Pr),
+            "br" => Ok(AppAbbr::Br),
+            _ => Err("Unsupported application. Use: ps|ai|ae|id|pr|br".into()),
         }
     }
 }
 
+/// AppleScript idiom an app uses to run an ExtendScript file.
+#[cfg(target_os = "macos")]
+enum ScriptDialect {
+    /// `do javascript of (POSIX file "...")` — Photoshop, Illustrator.
+    JavaScript,
+    /// `DoScriptFile (POSIX file "...")` — After Effects.
+    ScriptFile,
+    /// `do script (POSIX file "...") language javascript` — InDesign, Premiere, Bridge.
+    Script,
+}
+
 impl AppAbbr {
+    /// Every known application abbreviation, in CLI order.
+    const ALL: [AppAbbr; 6] = [
+        AppAbbr::Ps,
+        AppAbbr::Ai,
+        AppAbbr::Ae,
+        AppAbbr::Id,
+        AppAbbr::Pr,
+        AppAbbr::Br,
+    ];
+
+    /// Get the lowercase abbreviation as passed on the command line
+    fn as_str(&self) -> &'static str {
+        match self {
+            AppAbbr::Ps => "ps",
+            AppAbbr::Ai => "ai",
+            AppAbbr::Ae => "ae",
+            AppAbbr::Id => "id",
+            AppAbbr::Pr => "pr",
+            AppAbbr::Br => "br",
+        }
+    }
+
     /// Get the base display name of the application
     fn base_display_name(&self) -> &'static str {
         match self {
             AppAbbr::Ps => "Adobe Photoshop",
             AppAbbr::Ai => "Adobe Illustrator",
             AppAbbr::Ae => "Adobe After Effects",
+            AppAbbr::Id => "Adobe InDesign",
+            AppAbbr::Pr => "Adobe Premiere Pro",
+            AppAbbr::Br => "Adobe Bridge",
         }
     }
 
-    /// Get the bundle ID of the application
+    /// Candidate `CFBundleIdentifier`s for the application (case sensitive).
+    ///
+    /// Creative Cloud and Mac App Store builds expose different identifiers, so
+    /// each app declares the full set; discovery matches any of them.
+    fn bundle_ids(&self) -> &'static [&'static str] {
+        match self {
+            AppAbbr::Ps => &["com.adobe.Photoshop", "com.adobe.photoshop"],
+            AppAbbr::Ai => &["com.adobe.Illustrator", "com.adobe.illustrator"],
+            AppAbbr::Ae => &["com.adobe.AfterEffects", "com.adobe.aftereffects"],
+            AppAbbr::Id => &["com.adobe.InDesign", "com.adobe.indesign"],
+            AppAbbr::Pr => &["com.adobe.PremierePro", "com.adobe.premierepro"],
+            AppAbbr::Br => &["com.adobe.bridge", "com.adobe.Bridge"],
+        }
+    }
+
+    /// Primary bundle identifier, used for display in messages and `info` output.
     fn bundle_id(&self) -> &'static str {
-        // Use actual CFBundleIdentifier values (case sensitive)
+        self.bundle_ids()[0]
+    }
+
+    /// Windows executable name, the platform counterpart to [`bundle_ids`].
+    ///
+    /// Adobe installs each app under `%ProgramFiles%\Adobe\<App>\<exe>`; this is
+    /// the leaf binary Windows discovery looks for.
+    ///
+    /// [`bundle_ids`]: AppAbbr::bundle_ids
+    #[cfg(target_os = "windows")]
+    fn windows_exe_name(&self) -> &'static str {
+        match self {
+            AppAbbr::Ps => "Photoshop.exe",
+            AppAbbr::Ai => "Illustrator.exe",
+            AppAbbr::Ae => "AfterFX.exe",
+            AppAbbr::Id => "InDesign.exe",
+            AppAbbr::Pr => "Adobe Premiere Pro.exe",
+            AppAbbr::Br => "Adobe Bridge.exe",
+        }
+    }
+
+    /// Script extensions this application can run.
+    ///
+    /// `.psjs` is Photoshop-only, and After Effects rejects plain `.js`,
+    /// driving only `.jsx` through `DoScriptFile`.
+    fn supported_script_types(&self) -> &'static [&'static str] {
+        match self {
+            AppAbbr::Ps => &["psjs", "jsx", "js"],
+            AppAbbr::Ai => &["jsx", "js"],
+            AppAbbr::Ae => &["jsx"],
+            AppAbbr::Id => &["jsx", "js"],
+            AppAbbr::Pr => &["jsx"],
+            AppAbbr::Br => &["jsx", "js"],
+        }
+    }
+
+    /// Whether this app can run the given script type.
+    fn supports(&self, script_type: &ScriptType) -> bool {
+        self.supported_script_types()
+            .contains(&script_type.extension())
+    }
+
+    /// AppleScript dialect this app uses to run an ExtendScript file.
+    #[cfg(target_os = "macos")]
+    fn applescript_dialect(&self) -> ScriptDialect {
         match self {
-            AppAbbr::Ps => "com.adobe.Photoshop",
-            AppAbbr::Ai => "com.adobe.Illustrator",
-            AppAbbr::Ae => "com.adobe.AfterEffects",
+            AppAbbr::Ps | AppAbbr::Ai => ScriptDialect::JavaScript,
+            AppAbbr::Ae => ScriptDialect::ScriptFile,
+            AppAbbr::Id | AppAbbr::Pr | AppAbbr::Br => ScriptDialect::Script,
         }
     }
 }
 
+/// Parse the trailing version token out of a resolved `.app` name.
+///
+/// Returns whether the build is a `(Beta)` install and the `20XX` release
+/// year, when one is present (e.g. "Adobe Photoshop 2024").
+///
+/// Expects the install's own name component (see [`install_display_name`]), not
+/// a full path: it picks the *trailing* `20XX` token so an ancestor directory
+/// containing a year can't masquerade as the version.
+fn parse_version_token(name: &str) -> (bool, Option<u16>) {
+    let is_beta = name.contains("(Beta)");
+    let year = name
+        .split(|c: char| !c.is_ascii_digit())
+        .rfind(|tok| tok.len() == 4 && tok.starts_with("20"))
+        .and_then(|tok| tok.parse::<u16>().ok());
+    (is_beta, year)
+}
+
+/// The version-bearing name component of an install path.
+///
+/// On macOS the `.app` bundle carries the year (`Adobe Photoshop 2024.app`); on
+/// Windows the leaf is a generic executable, so the parent install folder
+/// (`Adobe Photoshop 2024\Photoshop.exe`) holds it instead.
+#[cfg(target_os = "macos")]
+fn install_display_name(path: &Path) -> String {
+    path.file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+/// The version-bearing name component of an install path.
+#[cfg(target_os = "windows")]
+fn install_display_name(path: &Path) -> String {
+    path.parent()
+        .and_then(|p| p.file_name())
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
 /// Represents an application
 struct App {
     /// Application abbreviation
     abbr: AppAbbr,
-    /// Bundle ID of the application
-    bundle_id: String,
     /// Name of the application
     name: String, // e.g. "Adobe Photoshop 2024" or "Adobe Photoshop (Beta)"
     // Full .app path
@@ -136,6 +399,7 @@ impl fmt::Display for App {
             TargetVersion::Latest => write!(f, "{} [latest]", self.name),
             TargetVersion::Beta => write!(f, "{} [beta]", self.name),
             TargetVersion::Year(y) => write!(f, "{} [{}]", self.name, y),
+            TargetVersion::AtLeast(y) => write!(f, "{} [>={}]", self.name, y),
         }
     }
 }
@@ -156,33 +420,139 @@ fn mdfind_apps(bundle_id: &str) -> Result<Vec<PathBuf>, Box<dyn Error>> {
     Ok(paths)
 }
 
+/// Install roots scanned when Spotlight can't locate an app.
 #[cfg(target_os = "macos")]
-/// Choose the app path based on the target version
-fn choose_app_path(paths: &[PathBuf], target: &TargetVersion) -> Option<PathBuf> {
-    // Sort deterministically
-    let mut paths = paths.to_vec();
+fn default_app_roots() -> Vec<PathBuf> {
+    let mut roots = vec![PathBuf::from("/Applications")];
+    if let Some(home) = std::env::var_os("HOME") {
+        roots.push(PathBuf::from(home).join("Applications"));
+    }
+    roots
+}
+
+/// Read `CFBundleIdentifier` out of an `.app` bundle's `Info.plist`.
+///
+/// Delegates to `defaults`, which transparently handles both XML and binary
+/// plists, returning `None` when the key or file is absent.
+#[cfg(target_os = "macos")]
+fn read_bundle_identifier(app: &Path) -> Option<String> {
+    let info = app.join("Contents/Info");
+    let output = std::process::Command::new("defaults")
+        .arg("read")
+        .arg(&info)
+        .arg("CFBundleIdentifier")
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let id = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!id.is_empty()).then_some(id)
+}
+
+/// Scan the default install roots plus `extra_dirs` (and the directory one level
+/// below each, where Adobe nests its bundles) for `.app`s whose
+/// `CFBundleIdentifier` matches `bundle_id`.
+#[cfg(target_os = "macos")]
+fn scan_app_roots(bundle_id: &str, extra_dirs: &[PathBuf]) -> Vec<PathBuf> {
+    let is_app = |p: &Path| p.extension().map(|e| e == "app").unwrap_or(false);
+    let matches_id = |p: &Path| read_bundle_identifier(p).as_deref() == Some(bundle_id);
+
+    let mut found = Vec::new();
+    let mut roots = default_app_roots();
+    roots.extend(extra_dirs.iter().cloned());
+    for root in roots {
+        let Ok(entries) = std::fs::read_dir(&root) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if is_app(&path) {
+                if matches_id(&path) {
+                    found.push(path);
+                }
+            } else if path.is_dir() {
+                // Adobe installs as /Applications/<App>/<App>.app.
+                if let Ok(sub) = std::fs::read_dir(&path) {
+                    for child in sub.flatten() {
+                        let child = child.path();
+                        if is_app(&child) && matches_id(&child) {
+                            found.push(child);
+                        }
+                    }
+                }
+            }
+        }
+    }
+    found
+}
+
+/// Discover every install of `abbr`, preferring Spotlight and falling back to a
+/// filesystem scan of known roots when `mdfind` is empty or unavailable.
+#[cfg(target_os = "macos")]
+fn discover_app_paths(abbr: &AppAbbr, extra_dirs: &[PathBuf]) -> Vec<PathBuf> {
+    let mut paths = Vec::new();
+    for id in abbr.bundle_ids() {
+        paths.extend(mdfind_apps(id).unwrap_or_default());
+    }
+    if paths.is_empty() {
+        for id in abbr.bundle_ids() {
+            paths.extend(scan_app_roots(id, extra_dirs));
+        }
+    }
     paths.sort();
+    paths.dedup();
+    paths
+}
 
-    let is_beta = |p: &PathBuf| {
-        p.file_name()
-            .map(|n| n.to_string_lossy().contains("(Beta)"))
-            .unwrap_or(false)
+/// Choose the app path based on the target version.
+///
+/// Each candidate is ranked by the `(is_beta, year)` key parsed out of its
+/// version-bearing name component (see [`install_display_name`] and
+/// [`parse_version_token`]) — never the full path, so a `20xx` in an ancestor
+/// directory can't win. A parseable year always beats an ambiguous path, and
+/// non-beta builds win ties against betas. Selection then maximises that key
+/// within the subset the target admits — analogous to a tag-priority match.
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+fn choose_app_path(paths: &[PathBuf], target: &TargetVersion) -> Option<PathBuf> {
+    // (path, is_beta, year). Sorted by path first so ties resolve deterministically.
+    let mut ranked: Vec<(PathBuf, bool, Option<u16>)> = paths
+        .iter()
+        .map(|p| {
+            let (is_beta, year) = parse_version_token(&install_display_name(p));
+            (p.clone(), is_beta, year)
+        })
+        .collect();
+    ranked.sort_by(|a, b| a.0.cmp(&b.0));
+
+    // Highest year wins (an unparseable `None` sorts below any `Some`), then
+    // non-beta wins ties.
+    let best = |cands: &[&(PathBuf, bool, Option<u16>)]| -> Option<PathBuf> {
+        cands
+            .iter()
+            .max_by(|a, b| (a.2, !a.1).cmp(&(b.2, !b.1)))
+            .map(|c| c.0.clone())
     };
 
     match target {
         TargetVersion::Latest => {
-            // Prefer non-beta, fallback to any
-            let latest_non_beta = paths.iter().filter(|p| !is_beta(p)).last().cloned();
-            latest_non_beta.or_else(|| paths.last().cloned())
+            let non_beta: Vec<_> = ranked.iter().filter(|c| !c.1).collect();
+            best(&non_beta).or_else(|| best(&ranked.iter().collect::<Vec<_>>()))
+        }
+        TargetVersion::Beta => {
+            let beta: Vec<_> = ranked.iter().filter(|c| c.1).collect();
+            best(&beta)
         }
-        TargetVersion::Beta => paths.iter().filter(|p| is_beta(p)).last().cloned(),
         TargetVersion::Year(y) => {
-            let y = y.to_string();
-            paths.into_iter().rev().find(|p| {
-                p.file_name()
-                    .map(|n| n.to_string_lossy().contains(&y))
-                    .unwrap_or(false)
-            })
+            let exact: Vec<_> = ranked.iter().filter(|c| c.2 == Some(*y)).collect();
+            best(&exact)
+        }
+        TargetVersion::AtLeast(y) => {
+            let ok: Vec<_> = ranked
+                .iter()
+                .filter(|c| c.2.map(|yy| yy >= *y).unwrap_or(false))
+                .collect();
+            best(&ok)
         }
     }
 }
@@ -191,14 +561,17 @@ fn choose_app_path(paths: &[PathBuf], target: &TargetVersion) -> Option<PathBuf>
 /// Create a new App struct
 impl App {
     /// Create a new App struct
-    fn new(abbr: AppAbbr, target: TargetVersion) -> Result<Self, Box<dyn Error>> {
-        let bundle_id = abbr.bundle_id().to_string();
-        let candidates = mdfind_apps(&bundle_id)?;
+    fn new(
+        abbr: AppAbbr,
+        target: TargetVersion,
+        extra_dirs: &[PathBuf],
+    ) -> Result<Self, Box<dyn Error>> {
+        let candidates = discover_app_paths(&abbr, extra_dirs);
         if candidates.is_empty() {
             return Err(format!(
                 "{} not found (bundle id: {})",
                 abbr.base_display_name(),
-                bundle_id
+                abbr.bundle_id()
             )
             .into());
         }
@@ -210,7 +583,82 @@ impl App {
             .ok_or("Failed to determine app name")?;
         Ok(App {
             abbr,
-            bundle_id,
+            name,
+            path: chosen,
+            target,
+        })
+    }
+}
+
+/// Install roots probed on Windows: `%ProgramFiles%\Adobe` and its 32-bit twin.
+#[cfg(target_os = "windows")]
+fn windows_app_roots() -> Vec<PathBuf> {
+    let mut roots = Vec::new();
+    for var in ["ProgramFiles", "ProgramFiles(x86)"] {
+        if let Some(v) = std::env::var_os(var) {
+            roots.push(PathBuf::from(v).join("Adobe"));
+        }
+    }
+    roots
+}
+
+/// Locate the Adobe executable for `abbr` under the known Windows install roots.
+///
+/// Mirrors how a tool resolves a binary that can live at several fixed paths:
+/// each `Adobe\<App>\<exe>` candidate is probed and the existing ones returned.
+#[cfg(target_os = "windows")]
+fn discover_app_paths_windows(abbr: &AppAbbr, extra_dirs: &[PathBuf]) -> Vec<PathBuf> {
+    let exe = abbr.windows_exe_name();
+    let mut found = Vec::new();
+    let mut roots = windows_app_roots();
+    roots.extend(extra_dirs.iter().cloned());
+    for root in roots {
+        let Ok(entries) = std::fs::read_dir(&root) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let dir = entry.path();
+            if dir.is_dir() {
+                let candidate = dir.join(exe);
+                if candidate.is_file() {
+                    found.push(candidate);
+                }
+            }
+        }
+    }
+    found.sort();
+    found.dedup();
+    found
+}
+
+#[cfg(target_os = "windows")]
+/// Create a new App struct
+impl App {
+    /// Create a new App struct
+    fn new(
+        abbr: AppAbbr,
+        target: TargetVersion,
+        extra_dirs: &[PathBuf],
+    ) -> Result<Self, Box<dyn Error>> {
+        let candidates = discover_app_paths_windows(&abbr, extra_dirs);
+        if candidates.is_empty() {
+            return Err(format!(
+                "{} not found (exe: {})",
+                abbr.base_display_name(),
+                abbr.windows_exe_name()
+            )
+            .into());
+        }
+        let chosen =
+            choose_app_path(&candidates, &target).ok_or("Requested target version not found")?;
+        // The version lives in the install folder, not the exe name.
+        let name = chosen
+            .parent()
+            .and_then(|p| p.file_name())
+            .map(|s| s.to_string_lossy().to_string())
+            .ok_or("Failed to determine app name")?;
+        Ok(App {
+            abbr,
             name,
             path: chosen,
             target,
@@ -233,25 +681,77 @@ struct Script {
     script_type: ScriptType,
     /// Verbose mode
     verbose: bool,
+    /// Execution timeout in seconds (0 = wait indefinitely)
+    timeout: u64,
 }
 
 impl Script {
     /// Create a new Script struct
-    fn new(app: App, file_path: &Path, script_type: ScriptType, verbose: bool) -> Script {
+    fn new(
+        app: App,
+        file_path: &Path,
+        script_type: ScriptType,
+        verbose: bool,
+        timeout: u64,
+    ) -> Script {
         Script {
             app,
             file_path: file_path.to_owned(),
             script_type,
             verbose,
+            timeout,
         }
     }
 
-    #[cfg(target_os = "macos")]
-    /// Run a command and print the output if verbose mode is enabled
-    /// Executes a script using osascript
-    /// Executes a script using `open -a` (useful for .psjs in Photoshop)
+    #[cfg(any(target_os = "macos", target_os = "windows"))]
+    /// Run a command, enforcing the timeout and printing output when verbose.
     fn run_cmd(&self, mut cmd: std::process::Command) -> Result<(), Box<dyn Error>> {
-        let output = cmd.output()?;
+        let output = if self.timeout == 0 {
+            cmd.output()?
+        } else {
+            // Spawn and poll so a stuck osascript / modal Adobe dialog can't hang
+            // heyps forever. stdout/stderr are drained on reader threads while we
+            // poll: a child writing more than the OS pipe buffer would otherwise
+            // block on write() and try_wait would never observe it exiting.
+            use std::io::Read;
+            cmd.stdout(std::process::Stdio::piped())
+                .stderr(std::process::Stdio::piped());
+            let mut child = cmd.spawn()?;
+            let mut stdout = child.stdout.take().ok_or("Failed to capture stdout")?;
+            let mut stderr = child.stderr.take().ok_or("Failed to capture stderr")?;
+            let stdout_reader = std::thread::spawn(move || {
+                let mut buf = Vec::new();
+                let _ = stdout.read_to_end(&mut buf);
+                buf
+            });
+            let stderr_reader = std::thread::spawn(move || {
+                let mut buf = Vec::new();
+                let _ = stderr.read_to_end(&mut buf);
+                buf
+            });
+            let deadline =
+                std::time::Instant::now() + std::time::Duration::from_secs(self.timeout);
+            let status = loop {
+                if let Some(status) = child.try_wait()? {
+                    break status;
+                }
+                if std::time::Instant::now() >= deadline {
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    let _ = stdout_reader.join();
+                    let _ = stderr_reader.join();
+                    return Err(format!("timed out after {} s", self.timeout).into());
+                }
+                std::thread::sleep(std::time::Duration::from_millis(100));
+            };
+            let stdout = stdout_reader.join().map_err(|_| "stdout reader panicked")?;
+            let stderr = stderr_reader.join().map_err(|_| "stderr reader panicked")?;
+            std::process::Output {
+                status,
+                stdout,
+                stderr,
+            }
+        };
         if self.verbose {
             let stdout = String::from_utf8_lossy(&output.stdout);
             let stderr = String::from_utf8_lossy(&output.stderr);
@@ -273,80 +773,274 @@ impl Script {
         }
     }
 
-    /// Executes a script using osascript
-    #[cfg(target_os = "macos")]
-    /// Executes a script using osascript
-    fn execute_with_osascript(&self) -> Result<(), Box<dyn Error>> {
-        let path = self.file_path.to_string_lossy();
-        let escaped = escape_applescript_string(&path);
-
-        // Choose AppleScript command per app
-        let tell_cmd = match self.app.abbr {
-            AppAbbr::Ps | AppAbbr::Ai => format!(
-                "tell application \"{}\" to do javascript of (POSIX file \"{}\")",
-                self.app.name, escaped
-            ),
-            AppAbbr::Ae => {
-                if matches!(self.script_type, ScriptType::Js) {
-                    return Err("After Effects does not support plain .js; use .jsx".into());
+    /// Executes the script by dispatching to the platform [`Launcher`].
+    #[cfg(any(target_os = "macos", target_os = "windows"))]
+    fn execute(&self) -> Result<(), Box<dyn Error>> {
+        let launcher = platform_launcher();
+        match self.script_type {
+            ScriptType::Psjs => {
+                if !matches!(self.app.abbr, AppAbbr::Ps) {
+                    return Err(".psjs is only supported by Adobe Photoshop".into());
                 }
-                format!(
-                    "tell application \"{}\" to DoScriptFile (POSIX file \"{}\")",
-                    self.app.name, escaped
-                )
+                launcher.open_with_app(self)
             }
+            ScriptType::Jsx | ScriptType::Js => launcher.run_extendscript(self),
+        }
+    }
+}
+
+/// Platform backend that knows how to drive a resolved Adobe app.
+///
+/// Abstracts the two operations the tool needs — running a `.jsx`/`.js`
+/// ExtendScript inside a named app, and opening a `.psjs` with the resolved
+/// app binary — so the execution path is no longer tied to macOS.
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+trait Launcher {
+    /// Run the `.jsx`/`.js` ExtendScript in the script's target app.
+    fn run_extendscript(&self, script: &Script) -> Result<(), Box<dyn Error>>;
+    /// Open the `.psjs` with the resolved app binary.
+    fn open_with_app(&self, script: &Script) -> Result<(), Box<dyn Error>>;
+}
+
+/// Returns the [`Launcher`] for the host platform.
+#[cfg(target_os = "macos")]
+fn platform_launcher() -> impl Launcher {
+    MacLauncher
+}
+
+/// Returns the [`Launcher`] for the host platform.
+#[cfg(target_os = "windows")]
+fn platform_launcher() -> impl Launcher {
+    WindowsLauncher
+}
+
+/// macOS backend driving ExtendScript through `osascript` and `.psjs` via `open`.
+#[cfg(target_os = "macos")]
+struct MacLauncher;
+
+#[cfg(target_os = "macos")]
+impl Launcher for MacLauncher {
+    fn run_extendscript(&self, script: &Script) -> Result<(), Box<dyn Error>> {
+        if !script.app.abbr.supports(&script.script_type) {
+            return Err(format!(
+                "{} does not support .{} scripts",
+                script.app.abbr.base_display_name(),
+                script.script_type.extension()
+            )
+            .into());
+        }
+
+        let escaped = escape_applescript_string(&script.file_path.to_string_lossy());
+        let name = &script.app.name;
+
+        // AppleScript idiom varies per app; it is declared in one place.
+        let tell_cmd = match script.app.abbr.applescript_dialect() {
+            ScriptDialect::JavaScript => format!(
+                "tell application \"{}\" to do javascript of (POSIX file \"{}\")",
+                name, escaped
+            ),
+            ScriptDialect::ScriptFile => format!(
+                "tell application \"{}\" to DoScriptFile (POSIX file \"{}\")",
+                name, escaped
+            ),
+            ScriptDialect::Script => format!(
+                "tell application \"{}\" to do script (POSIX file \"{}\") language javascript",
+                name, escaped
+            ),
         };
 
         let mut cmd = std::process::Command::new("osascript");
         cmd.arg("-e").arg(tell_cmd);
-        self.run_cmd(cmd)
+        script.run_cmd(cmd)
     }
 
-    /// Executes a script using `open -a` (useful for .psjs in Photoshop)
-    #[cfg(target_os = "macos")]
-    fn execute_with_open(&self) -> Result<(), Box<dyn Error>> {
+    fn open_with_app(&self, script: &Script) -> Result<(), Box<dyn Error>> {
         let mut cmd = std::process::Command::new("open");
         // Use the resolved full .app path to disambiguate versions
-        cmd.arg("-a").arg(&self.app.path).arg(&self.file_path);
-        self.run_cmd(cmd)
+        cmd.arg("-a").arg(&script.app.path).arg(&script.file_path);
+        script.run_cmd(cmd)
     }
+}
 
-    /// Executes the script based on its type and target application.
-    #[cfg(target_os = "macos")]
-    fn execute(&self) -> Result<(), Box<dyn Error>> {
-        match self.script_type {
-            ScriptType::Psjs => {
-                if !matches!(self.app.abbr, AppAbbr::Ps) {
-                    return Err(".psjs is only supported by Adobe Photoshop".into());
-                }
-                self.execute_with_open()
+/// Windows backend driving ExtendScript through the located Adobe executable.
+///
+/// The resolved `.exe` accepts a script file directly on the command line; a
+/// richer COM-automation path (`Photoshop.Application.DoJavaScriptFile`) could
+/// replace this if finer control over the host is ever needed.
+#[cfg(target_os = "windows")]
+struct WindowsLauncher;
+
+#[cfg(target_os = "windows")]
+impl Launcher for WindowsLauncher {
+    fn run_extendscript(&self, script: &Script) -> Result<(), Box<dyn Error>> {
+        if !script.app.abbr.supports(&script.script_type) {
+            return Err(format!(
+                "{} does not support .{} scripts",
+                script.app.abbr.base_display_name(),
+                script.script_type.extension()
+            )
+            .into());
+        }
+        let mut cmd = std::process::Command::new(&script.app.path);
+        cmd.arg(&script.file_path);
+        script.run_cmd(cmd)
+    }
+
+    fn open_with_app(&self, script: &Script) -> Result<(), Box<dyn Error>> {
+        // .psjs are handed to the resolved Photoshop executable just like .jsx.
+        let mut cmd = std::process::Command::new(&script.app.path);
+        cmd.arg(&script.file_path);
+        script.run_cmd(cmd)
+    }
+}
+
+/// Discover every install of `abbr` using the host platform's strategy.
+#[cfg(target_os = "macos")]
+fn discover_installs(abbr: &AppAbbr, extra_dirs: &[PathBuf]) -> Vec<PathBuf> {
+    discover_app_paths(abbr, extra_dirs)
+}
+
+/// Discover every install of `abbr` using the host platform's strategy.
+#[cfg(target_os = "windows")]
+fn discover_installs(abbr: &AppAbbr, extra_dirs: &[PathBuf]) -> Vec<PathBuf> {
+    discover_app_paths_windows(abbr, extra_dirs)
+}
+
+/// Escape a string for inclusion in a JSON document.
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+fn escape_json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Enumerate every installed Adobe app discovered on this platform.
+///
+/// For each [`AppAbbr`] this runs the platform discovery (see
+/// [`discover_installs`]) and reports the resolved name, parsed year/beta
+/// status, bundle id and full path, so users can see exactly what is installed
+/// before running a script. With `json` the same data is emitted as a
+/// machine-readable array.
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+fn cmd_info(json: bool, extra_dirs: &[PathBuf]) -> Result<(), Box<dyn Error>> {
+    // (abbr, name, is_beta, year, path)
+    let mut rows: Vec<(&'static AppAbbr, String, bool, Option<u16>, String)> = Vec::new();
+    for abbr in &AppAbbr::ALL {
+        for path in discover_installs(abbr, extra_dirs) {
+            // Resolve the name the same way App::new does, per platform.
+            let name = install_display_name(&path);
+            let (is_beta, year) = parse_version_token(&name);
+            rows.push((abbr, name, is_beta, year, path.to_string_lossy().into_owned()));
+        }
+    }
+
+    if json {
+        let mut out = String::from("[");
+        for (i, (abbr, name, is_beta, year, path)) in rows.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
             }
-            ScriptType::Jsx | ScriptType::Js => self.execute_with_osascript(),
+            let year = match year {
+                Some(y) => y.to_string(),
+                None => "null".to_string(),
+            };
+            out.push_str(&format!(
+                "{{\"abbr\":\"{}\",\"name\":\"{}\",\"beta\":{},\"year\":{},\"bundle_id\":\"{}\",\"path\":\"{}\",\"script_types\":[{}]}}",
+                abbr.as_str(),
+                escape_json_string(name),
+                is_beta,
+                year,
+                escape_json_string(abbr.bundle_id()),
+                escape_json_string(path),
+                abbr.supported_script_types()
+                    .iter()
+                    .map(|t| format!("\"{}\"", t))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            ));
         }
+        out.push(']');
+        println!("{}", out);
+        return Ok(());
+    }
+
+    if rows.is_empty() {
+        println!("No Adobe applications found.");
+        return Ok(());
+    }
+
+    println!(
+        "{:<5} {:<32} {:<6} {:<6} {:<24} PATH",
+        "ABBR", "NAME", "YEAR", "BETA", "BUNDLE ID"
+    );
+    for (abbr, name, is_beta, year, path) in &rows {
+        let year = year.map(|y| y.to_string()).unwrap_or_else(|| "-".to_string());
+        println!(
+            "{:<5} {:<32} {:<6} {:<6} {:<24} {}",
+            abbr.as_str(),
+            name,
+            year,
+            if *is_beta { "yes" } else { "no" },
+            abbr.bundle_id(),
+            path
+        );
+    }
+
+    // Summarise per-app scripting support so callers know, e.g., that After
+    // Effects rejects plain `.js`.
+    println!("\nSupported script types:");
+    for abbr in &AppAbbr::ALL {
+        println!(
+            "  {:<3} {}",
+            abbr.as_str(),
+            abbr.supported_script_types().join(", ")
+        );
     }
+    Ok(())
 }
 
-#[cfg(not(target_os = "macos"))]
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
 fn main() -> Result<(), Box<dyn Error>> {
-    eprintln!("heyps currently supports macOS only.");
+    eprintln!("heyps currently supports macOS and Windows only.");
     std::process::exit(1);
 }
 
-#[cfg(target_os = "macos")]
-fn main() -> Result<(), Box<dyn Error>> {
-    let matches = Command::new("heyps")
+/// Build the `heyps` CLI definition, shared by `main` and the precedence tests.
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+fn build_cli() -> Command {
+    Command::new("heyps")
         .version(env!("CARGO_PKG_VERSION"))
         .author(env!("CARGO_PKG_AUTHORS"))
         .about("Execute Adobe app scripts from the terminal")
+        .subcommand_negates_reqs(true)
+        .subcommand(
+            Command::new("info")
+                .about("List installed Adobe apps and the script types they support")
+                .arg(
+                    Arg::new("json")
+                        .long("json")
+                        .action(ArgAction::SetTrue)
+                        .help("Emit the discovered apps as JSON"),
+                ),
+        )
         .arg(
             Arg::new("app")
                 .short('a')
                 .long("app")
                 .value_name("APP")
-                .required(true)
                 .default_value("ps")
-                .value_parser(["ps", "ai", "ae"])
-                .help("Target Adobe application: ps|ai|ae"),
+                .value_parser(["ps", "ai", "ae", "id", "pr", "br"])
+                .help("Target Adobe application: ps|ai|ae|id|pr|br"),
         )
         .arg(
             Arg::new("target")
@@ -355,7 +1049,7 @@ fn main() -> Result<(), Box<dyn Error>> {
                 .value_name("TARGET")
                 .default_value("latest")
                 .value_parser(ValueParser::new(parse_target))
-                .help("Target version: latest, beta, or 20XX (e.g., 2024)"),
+                .help("Target version: latest, beta, 20XX, or >=20XX (e.g., 2024, >=2022)"),
         )
         .arg(
             Arg::new("execute")
@@ -365,6 +1059,13 @@ fn main() -> Result<(), Box<dyn Error>> {
                 .required(true)
                 .help("Path to the script file (.psjs, .jsx, .js)"),
         )
+        .arg(
+            Arg::new("timeout")
+                .long("timeout")
+                .value_name("SECONDS")
+                .value_parser(clap::value_parser!(u64))
+                .help("Abort execution after N seconds (0 = wait indefinitely)"),
+        )
         .arg(
             Arg::new("verbose")
                 .short('v')
@@ -380,7 +1081,15 @@ fn main() -> Result<(), Box<dyn Error>> {
                 .hide(true) // keep for future use, hidden for now
                 .help("Runs the test script (reserved)"),
         )
-        .get_matches();
+}
+
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+fn main() -> Result<(), Box<dyn Error>> {
+    let matches = build_cli().get_matches();
+
+    if let Some(info_m) = matches.subcommand_matches("info") {
+        return cmd_info(info_m.get_flag("json"), &load_config().extra_app_dirs);
+    }
 
     let file_path = PathBuf::from(matches.get_one::<String>("execute").unwrap());
     if !file_path.exists() {
@@ -391,19 +1100,41 @@ fn main() -> Result<(), Box<dyn Error>> {
     }
 
     let script_type = ScriptType::from_path(&file_path)?;
-    let app_abbr = matches
-        .get_one::<String>("app")
-        .unwrap()
-        .parse::<AppAbbr>()
-        .map_err(|e| format!("Invalid app: {}", e))?;
-    let target = matches
-        .get_one::<TargetVersion>("target")
-        .expect("defaulted")
-        .clone();
-    let verbose = matches.get_flag("verbose");
-
-    let app = App::new(app_abbr, target)?;
-    let script = Script::new(app, &file_path, script_type, verbose);
+
+    // Resolve defaults: config file < HEYPS_* env < explicit CLI flag.
+    let cfg = load_config();
+
+    let app_abbr = if matches.value_source("app") == Some(ValueSource::CommandLine) {
+        matches.get_one::<String>("app").unwrap().clone()
+    } else {
+        cfg.app.clone()
+    }
+    .parse::<AppAbbr>()
+    .map_err(|e| format!("Invalid app: {}", e))?;
+
+    let target = if matches.value_source("target") == Some(ValueSource::CommandLine) {
+        matches
+            .get_one::<TargetVersion>("target")
+            .expect("defaulted")
+            .clone()
+    } else {
+        parse_target(&cfg.target).map_err(|e| format!("Invalid config target: {}", e))?
+    };
+
+    let timeout = if matches.value_source("timeout") == Some(ValueSource::CommandLine) {
+        *matches.get_one::<u64>("timeout").unwrap()
+    } else {
+        cfg.timeout
+    };
+
+    let verbose = if matches.value_source("verbose") == Some(ValueSource::CommandLine) {
+        true
+    } else {
+        cfg.verbose
+    };
+
+    let app = App::new(app_abbr, target, &cfg.extra_app_dirs)?;
+    let script = Script::new(app, &file_path, script_type, verbose, timeout);
 
     if verbose {
         eprintln!("[heyps] Using app: {}", script.app);
@@ -414,3 +1145,88 @@ fn main() -> Result<(), Box<dyn Error>> {
     script.execute()?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_target_forms() {
+        assert!(matches!(parse_target("latest"), Ok(TargetVersion::Latest)));
+        assert!(matches!(parse_target("beta"), Ok(TargetVersion::Beta)));
+        assert!(matches!(parse_target("2024"), Ok(TargetVersion::Year(2024))));
+        assert!(matches!(parse_target(">=2022"), Ok(TargetVersion::AtLeast(2022))));
+        assert!(parse_target("2024x").is_err());
+        assert!(parse_target(">=42").is_err());
+        assert!(parse_target("nonsense").is_err());
+    }
+
+    #[test]
+    fn version_token_picks_trailing_year() {
+        assert_eq!(parse_version_token("Adobe Photoshop 2024"), (false, Some(2024)));
+        assert_eq!(
+            parse_version_token("Adobe Photoshop 2024 (Beta)"),
+            (true, Some(2024))
+        );
+        assert_eq!(parse_version_token("Adobe Photoshop"), (false, None));
+        // A year earlier in the string must not win over the trailing token.
+        assert_eq!(
+            parse_version_token("alice2009 Adobe Photoshop 2024"),
+            (false, Some(2024))
+        );
+    }
+
+    #[cfg(target_os = "macos")]
+    #[test]
+    fn choose_app_path_ranks_by_version() {
+        let paths = [
+            // Ancestor directory carries a misleading year; must be ignored.
+            PathBuf::from("/Users/alice2009/Applications/Adobe Photoshop 2023.app"),
+            PathBuf::from("/Applications/Adobe Photoshop 2024.app"),
+            PathBuf::from("/Applications/Adobe Photoshop (Beta).app"),
+            PathBuf::from("/Applications/Adobe Photoshop.app"),
+        ];
+
+        let pick = |t| choose_app_path(&paths, &t).unwrap();
+        assert!(pick(TargetVersion::Latest).ends_with("Adobe Photoshop 2024.app"));
+        assert!(pick(TargetVersion::Beta).ends_with("Adobe Photoshop (Beta).app"));
+        assert!(pick(TargetVersion::Year(2023)).ends_with("Adobe Photoshop 2023.app"));
+        assert!(pick(TargetVersion::AtLeast(2024)).ends_with("Adobe Photoshop 2024.app"));
+        // No install satisfies the lower bound.
+        assert!(choose_app_path(&paths, &TargetVersion::AtLeast(2030)).is_none());
+    }
+
+    #[test]
+    fn config_file_parses_known_keys_last_wins() {
+        let mut cfg = Config::default();
+        apply_config_file(
+            &mut cfg,
+            "# comment\n[section]\napp = \"ai\"\ntarget = 2024\ntimeout = 30\nverbose = yes\napp = \"ae\"\n",
+        );
+        assert_eq!(cfg.app, "ae"); // later key wins
+        assert_eq!(cfg.target, "2024");
+        assert_eq!(cfg.timeout, 30);
+        assert!(cfg.verbose);
+    }
+
+    #[cfg(any(target_os = "macos", target_os = "windows"))]
+    #[test]
+    fn omitted_app_flag_falls_through_to_config_layer() {
+        // Regression test: `app` must NOT be `.required(true)`, or clap never
+        // reports `ValueSource::DefaultValue` and the config/HEYPS_APP layer
+        // in main() is unreachable dead code.
+        let matches = build_cli().get_matches_from(["heyps", "-e", "x.jsx"]);
+        assert_ne!(matches.value_source("app"), Some(ValueSource::CommandLine));
+
+        let cfg = Config {
+            app: "ai".to_string(),
+            ..Config::default()
+        };
+        let app_abbr = if matches.value_source("app") == Some(ValueSource::CommandLine) {
+            matches.get_one::<String>("app").unwrap().clone()
+        } else {
+            cfg.app.clone()
+        };
+        assert_eq!(app_abbr, "ai");
+    }
+}